@@ -0,0 +1,127 @@
+// Sign-In With Solana helpers shared by the admin auth endpoints
+// File: api/_shared/siws.js
+
+import crypto from 'crypto';
+
+export const SIWS_STATEMENT = 'Sign in to the TokenWars admin panel.';
+export const NONCE_TTL_MS = 5 * 60 * 1000; // 5 minutes
+
+const BASE58_ALPHABET = '123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz';
+const ED25519_SPKI_PREFIX = Buffer.from('302a300506032b6570032100', 'hex');
+
+/**
+ * Build the SIWS message - must match buildSiwsMessage in docs/admin/auth.js
+ */
+export function buildSiwsMessage({ domain, address, uri, nonce, issuedAt, expirationTime }) {
+  return [
+    `${domain} wants you to sign in with your Solana account:`,
+    address,
+    '',
+    SIWS_STATEMENT,
+    '',
+    `URI: ${uri}`,
+    'Version: 1',
+    `Nonce: ${nonce}`,
+    `Issued At: ${issuedAt}`,
+    `Expiration Time: ${expirationTime}`
+  ].join('\n');
+}
+
+function getSecret() {
+  const secret = process.env.ADMIN_AUTH_SECRET;
+  if (!secret) {
+    throw new Error('ADMIN_AUTH_SECRET is not configured');
+  }
+  return secret;
+}
+
+function hmac(payload) {
+  return crypto.createHmac('sha256', getSecret()).update(payload).digest('base64url');
+}
+
+/**
+ * Issue a stateless nonce token bound to a wallet (HMAC-signed, expires after NONCE_TTL_MS)
+ */
+export function issueNonce(walletAddress, now = Date.now()) {
+  const fields = {
+    walletAddress,
+    nonce: crypto.randomBytes(16).toString('hex'),
+    issuedAt: new Date(now).toISOString(),
+    expirationTime: new Date(now + NONCE_TTL_MS).toISOString()
+  };
+  const payload = Buffer.from(JSON.stringify(fields)).toString('base64url');
+
+  return { ...fields, nonceToken: `${payload}.${hmac(payload)}` };
+}
+
+/**
+ * Check a nonce token's HMAC and expiry, returns its fields or null
+ */
+export function readNonceToken(nonceToken, now = Date.now()) {
+  if (typeof nonceToken !== 'string') return null;
+
+  const [payload, mac] = nonceToken.split('.');
+  if (!payload || !mac) return null;
+
+  const expected = Buffer.from(hmac(payload));
+  const provided = Buffer.from(mac);
+  if (expected.length !== provided.length || !crypto.timingSafeEqual(expected, provided)) {
+    return null;
+  }
+
+  try {
+    const fields = JSON.parse(Buffer.from(payload, 'base64url').toString('utf8'));
+    if (Date.parse(fields.expirationTime) <= now) return null;
+    return fields;
+  } catch (error) {
+    return null;
+  }
+}
+
+function decodeBase58(value) {
+  const bytes = [];
+  for (const char of value) {
+    const digit = BASE58_ALPHABET.indexOf(char);
+    if (digit < 0) throw new Error('Invalid base58 character');
+
+    let carry = digit;
+    for (let i = 0; i < bytes.length; i++) {
+      carry += bytes[i] * 58;
+      bytes[i] = carry & 0xff;
+      carry >>= 8;
+    }
+    while (carry > 0) {
+      bytes.push(carry & 0xff);
+      carry >>= 8;
+    }
+  }
+
+  // Leading '1's are leading zero bytes
+  for (const char of value) {
+    if (char !== '1') break;
+    bytes.push(0);
+  }
+
+  return Buffer.from(bytes.reverse());
+}
+
+/**
+ * Verify an ed25519 signature (base64) over message by a base58 wallet address
+ */
+export function verifyWalletSignature(walletAddress, message, signatureBase64) {
+  try {
+    const publicKeyBytes = decodeBase58(walletAddress);
+    const signature = Buffer.from(signatureBase64, 'base64');
+    if (publicKeyBytes.length !== 32 || signature.length !== 64) return false;
+
+    const publicKey = crypto.createPublicKey({
+      key: Buffer.concat([ED25519_SPKI_PREFIX, publicKeyBytes]),
+      format: 'der',
+      type: 'spki'
+    });
+
+    return crypto.verify(null, Buffer.from(message, 'utf8'), publicKey, signature);
+  } catch (error) {
+    return false;
+  }
+}
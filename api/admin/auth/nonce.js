@@ -0,0 +1,52 @@
+// Vercel API endpoint issuing SIWS nonces for admin authentication
+// File: api/admin/auth/nonce.js
+
+import { issueNonce } from '../../_shared/siws.js';
+
+export default async function handler(req, res) {
+  // Set CORS headers first
+  res.setHeader('Access-Control-Allow-Origin', '*');
+  res.setHeader('Access-Control-Allow-Methods', 'POST, OPTIONS');
+  res.setHeader('Access-Control-Allow-Headers', 'Content-Type, Authorization');
+  res.setHeader('Content-Type', 'application/json');
+
+  // Handle preflight OPTIONS request
+  if (req.method === 'OPTIONS') {
+    return res.status(200).end();
+  }
+
+  // Only allow POST
+  if (req.method !== 'POST') {
+    return res.status(405).json({
+      success: false,
+      error: 'Method not allowed'
+    });
+  }
+
+  try {
+    const { walletAddress } = req.body;
+
+    if (!walletAddress) {
+      return res.status(400).json({
+        success: false,
+        message: 'Missing wallet address'
+      });
+    }
+
+    const nonce = issueNonce(walletAddress);
+    console.log('SIWS nonce issued for:', walletAddress);
+
+    return res.status(200).json({
+      success: true,
+      ...nonce
+    });
+
+  } catch (error) {
+    console.error('❌ Nonce API error:', error);
+    return res.status(500).json({
+      success: false,
+      message: 'Internal server error',
+      error: error.message
+    });
+  }
+}
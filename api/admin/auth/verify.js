@@ -1,6 +1,8 @@
 // Vercel API endpoint for admin authentication
 // File: api/admin/auth/verify.js
 
+import { buildSiwsMessage, readNonceToken, verifyWalletSignature } from '../../_shared/siws.js';
+
 export default async function handler(req, res) {
   // Set CORS headers first
   res.setHeader('Access-Control-Allow-Origin', '*');
@@ -24,8 +26,8 @@ export default async function handler(req, res) {
   try {
     console.log('=== ADMIN AUTH REQUEST ===');
     
-    const { walletAddress, pin } = req.body;
-    console.log('Auth attempt:', { walletAddress, pinProvided: !!pin });
+    const { walletAddress, pin, message, signature, nonceToken } = req.body;
+    console.log('Auth attempt:', { walletAddress, pinProvided: !!pin, signed: !!signature });
     
     // Validate input
     if (!walletAddress || !pin) {
@@ -45,6 +47,50 @@ export default async function handler(req, res) {
       });
     }
     
+    // Verify SIWS signature - proves the caller controls walletAddress
+    if (!message || !signature || !nonceToken) {
+      console.log('Missing SIWS signature');
+      return res.status(400).json({
+        success: false,
+        message: 'Missing signed sign-in message'
+      });
+    }
+    
+    const nonce = readNonceToken(nonceToken);
+    if (!nonce || nonce.walletAddress !== walletAddress) {
+      console.log('Invalid or expired nonce');
+      return res.status(401).json({
+        success: false,
+        message: 'Sign-in request expired. Please try again.'
+      });
+    }
+    
+    // Rebuild the expected message from the nonce and the browser-supplied Origin
+    const origin = req.headers.origin;
+    if (!origin) {
+      return res.status(400).json({
+        success: false,
+        message: 'Missing request origin'
+      });
+    }
+    
+    const expectedMessage = buildSiwsMessage({
+      domain: new URL(origin).host,
+      address: walletAddress,
+      uri: origin,
+      nonce: nonce.nonce,
+      issuedAt: nonce.issuedAt,
+      expirationTime: nonce.expirationTime
+    });
+    
+    if (message !== expectedMessage || !verifyWalletSignature(walletAddress, message, signature)) {
+      console.log('❌ SIWS signature verification failed');
+      return res.status(401).json({
+        success: false,
+        message: 'Wallet signature verification failed'
+      });
+    }
+    
     // Hardcoded admin credentials for Phase 1
     const ADMIN_WALLET = 'HmT6Nj3r24YKCxGLPFvf1gSJijXyNcrPHKKeknZYGRXv';
     const ADMIN_PIN = '999196';
@@ -1,6 +1,7 @@
 /**
  * Admin Authentication Module - Fixed for Vercel Deployment
  * Handles wallet + PIN authentication for admin access
+ * Wallet ownership is proven with a Sign-In With Solana (SIWS) message
  */
 
 // Authentication state
@@ -209,6 +210,64 @@ function handlePinInput(e) {
     }
 }
 
+/**
+ * Build the SIWS message - must match buildSiwsMessage in api/_shared/siws.js
+ */
+function buildSiwsMessage({ domain, address, uri, nonce, issuedAt, expirationTime }) {
+    return [
+        `${domain} wants you to sign in with your Solana account:`,
+        address,
+        '',
+        'Sign in to the TokenWars admin panel.',
+        '',
+        `URI: ${uri}`,
+        'Version: 1',
+        `Nonce: ${nonce}`,
+        `Issued At: ${issuedAt}`,
+        `Expiration Time: ${expirationTime}`
+    ].join('\n');
+}
+
+/**
+ * Request a nonce and have the connected wallet sign the SIWS message
+ */
+async function signInWithSolana(walletAddress) {
+    const nonceResponse = await fetch(`${window.location.origin}/api/admin/auth/nonce`, {
+        method: 'POST',
+        headers: {
+            'Content-Type': 'application/json',
+        },
+        body: JSON.stringify({ walletAddress })
+    });
+    
+    const nonceData = await nonceResponse.json();
+    if (!nonceResponse.ok || !nonceData.success) {
+        throw new Error(nonceData.message || 'Could not start wallet sign-in');
+    }
+    
+    const message = buildSiwsMessage({
+        domain: window.location.host,
+        address: walletAddress,
+        uri: window.location.origin,
+        nonce: nonceData.nonce,
+        issuedAt: nonceData.issuedAt,
+        expirationTime: nonceData.expirationTime
+    });
+    
+    if (!window.solana?.signMessage) {
+        throw new Error('Connected wallet does not support message signing');
+    }
+    
+    console.log('✍️ Requesting SIWS signature...');
+    const { signature } = await window.solana.signMessage(new TextEncoder().encode(message), 'utf8');
+    
+    return {
+        message,
+        signature: btoa(String.fromCharCode(...signature)),
+        nonceToken: nonceData.nonceToken
+    };
+}
+
 /**
  * Verify PIN with improved error handling
  */
@@ -237,12 +296,16 @@ async function verifyPin() {
         const apiUrl = `${window.location.origin}/api/admin/auth/verify`;
         console.log('Making request to:', apiUrl);
         
+        // Prove wallet ownership before the PIN is checked
+        const siws = await signInWithSolana(AuthState.walletAddress);
+        
         const requestData = {
             walletAddress: AuthState.walletAddress,
-            pin: pin
+            pin: pin,
+            ...siws
         };
         
-        console.log('Request data:', requestData);
+        console.log('Request data:', { walletAddress: requestData.walletAddress, signed: !!requestData.signature });
         
         const response = await fetch(apiUrl, {
             method: 'POST',
@@ -29,17 +29,19 @@ class SmartContractService {
         
         try {
             this.connection = new solanaWeb3.Connection(
-                'https://api.devnet.solana.com', 
+                window.BLOCKCHAIN_CONFIG?.RPC_URL || 'https://api.devnet.solana.com', 
                 {
                     commitment: 'confirmed',
                     confirmTransactionInitialTimeout: 60000,
-                    wsEndpoint: 'wss://api.devnet.solana.com'
+                    wsEndpoint: window.BLOCKCHAIN_CONFIG?.WS_URL || 'wss://api.devnet.solana.com'
                 }
             );
             this.programId = new solanaWeb3.PublicKey(
                 window.BLOCKCHAIN_CONFIG?.SOLANA_PROGRAM_ID || 'GQ5wLSCs3g62xDoaKxgTVtqjsSXHz3FXYjNpBZGDdpqm' 
             );
-            this.platformWallet = new solanaWeb3.PublicKey('HmT6Nj3r24YKCxGLPFvf1gSJijXyNcrPHKKeknZYGRXv');
+            this.platformWallet = new solanaWeb3.PublicKey(
+                window.BLOCKCHAIN_CONFIG?.PLATFORM_WALLET || 'HmT6Nj3r24YKCxGLPFvf1gSJijXyNcrPHKKeknZYGRXv'
+            );
             this.available = true;
             
             // FIXED: Correct Anchor discriminators for your deployed program
@@ -55,6 +57,7 @@ class SmartContractService {
             
             console.log('✅ Smart Contract Service initialized with correct Anchor discriminators');
             console.log('📋 Program ID:', this.programId.toString());
+            console.log('🌐 Cluster:', window.BLOCKCHAIN_CONFIG?.SOLANA_NETWORK || 'devnet');
             console.log('🔧 Discriminators loaded:', Object.keys(this.instructions));
             return true;
             
@@ -80,6 +83,132 @@ class SmartContractService {
         console.log(`🔧 REAL discriminator for ${functionName}:`, Array.from(discriminator));
         return Buffer.from(discriminator);
     }
+
+    // TokenWarsError catalog - codes are enum order, Anchor adds 6000 on-chain
+    static PROGRAM_ERRORS = {
+        0: { name: 'InvalidVotingEndTime', message: 'Voting end time is invalid', hint: 'Voting must end in the future, before the competition ends.' },
+        1: { name: 'InvalidCompetitionEndTime', message: 'Competition end time is invalid', hint: 'The competition must end after voting closes.' },
+        2: { name: 'InvalidPlatformFee', message: 'Platform fee is out of range', hint: 'Use a platform fee between 5% and 25%.' },
+        3: { name: 'InvalidCompetition', message: 'Competition not found or invalid', hint: 'Refresh the page - the competition may have been removed.' },
+        4: { name: 'VotingClosed', message: 'Betting is closed for this competition', hint: 'Pick a competition that is still in its voting period.' },
+        5: { name: 'VotingPeriodEnded', message: 'The voting period has ended', hint: 'Bets can only be placed before voting ends.' },
+        6: { name: 'InsufficientBetAmount', message: 'Bet amount is below the required amount', hint: 'Bet the exact amount shown for this competition.' },
+        7: { name: 'UserAlreadyBet', message: 'You have already bet on this competition', hint: 'Each wallet can place one bet per competition.' },
+        8: { name: 'Unauthorized', message: 'This wallet is not authorized for this action', hint: 'Connect the wallet that owns the bet or the admin wallet.' },
+        9: { name: 'VotingStillActive', message: 'Voting is still active', hint: 'Wait until voting ends and try again.' },
+        10: { name: 'InvalidStatus', message: 'Competition is not in the right state for this action', hint: 'Refresh to see the current competition status.' }
+    };
+
+    static PROGRAM_ERROR_OFFSET = 6000;
+
+    // Find a custom program error code in a thrown error, its logs, or a transaction err object
+    findCustomErrorCode(error) {
+        // confirmation.value.err / SendTransactionError shape: { InstructionError: [index, { Custom: n }] }
+        const instructionError = error?.InstructionError || error?.transactionError?.InstructionError;
+        const custom = instructionError?.[1]?.Custom;
+        if (Number.isInteger(custom)) {
+            return custom;
+        }
+        
+        const texts = [error?.message, ...(Array.isArray(error?.logs) ? error.logs : [])];
+        for (const text of texts) {
+            const hexCode = text?.match(/custom program error: 0x(\w+)/)?.[1];
+            if (hexCode) {
+                return parseInt(hexCode, 16);
+            }
+            const anchorCode = text?.match(/Error Number: (\d+)/)?.[1];
+            if (anchorCode) {
+                return parseInt(anchorCode, 10);
+            }
+        }
+        
+        return null;
+    }
+
+    // Extract TokenWarsError entry from a failed transaction
+    // Codes below 6000 come from other programs (e.g. System Program 0x1 = insufficient lamports) - return null so callers fall back
+    decodeProgramError(error) {
+        if (error?.programError) {
+            return error.programError;
+        }
+        
+        const rawCode = this.findCustomErrorCode(error);
+        if (rawCode === null) {
+            return null;
+        }
+        
+        const entry = SmartContractService.PROGRAM_ERRORS[rawCode - SmartContractService.PROGRAM_ERROR_OFFSET];
+        if (!entry) {
+            return null;
+        }
+        
+        console.error('🚨 Anchor program error code:', rawCode);
+        return { code: rawCode, ...entry };
+    }
+
+    // User-facing text for a decoded program error
+    formatProgramError(programError) {
+        return `${programError.message}. ${programError.hint}`;
+    }
+
+    // Error carrying the decoded TokenWarsError so callers can branch on code/name
+    toProgramError(programError, prefix = '') {
+        const error = new Error(`${prefix}${this.formatProgramError(programError)}`);
+        error.code = programError.code;
+        error.programError = programError;
+        return error;
+    }
+
+    // Throw if a confirmed transaction failed on-chain (confirmTransaction does not throw on its own)
+    assertConfirmed(confirmation) {
+        const err = confirmation?.value?.err;
+        if (!err) {
+            return;
+        }
+        
+        console.error('❌ Transaction failed on-chain:', err);
+        const programError = this.decodeProgramError(err);
+        if (programError) {
+            throw this.toProgramError(programError);
+        }
+        throw new Error(`Transaction failed on-chain: ${JSON.stringify(err)}`);
+    }
+
+    // PDA seeds - single source of truth, must match the deployed program
+    static ESCROW_SEED = "escrow";
+    static ESCROW_ID_MAX_LENGTH = 28;
+
+    // competition_id is a Supabase uuid column (admin generates it with crypto.randomUUID)
+    // Lowercase only - Postgres reads uuids back lowercase, and the seed uses the hex digits as-is
+    static COMPETITION_ID_PATTERN = /^[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}$/;
+
+    // Reject anything but a canonical lowercase UUID before it is used in PDA seeds
+    validateCompetitionId(competitionId) {
+        if (typeof competitionId !== 'string' || competitionId.length === 0) {
+            throw new Error('Competition ID is required');
+        }
+        if (!SmartContractService.COMPETITION_ID_PATTERN.test(competitionId)) {
+            throw new Error('Competition ID must be a lowercase UUID (xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx)');
+        }
+        return competitionId;
+    }
+
+    // Competition ID as used in escrow seeds and instruction data
+    // Deployed escrows use the first 28 hex digits of the UUID (last 4 dropped) - changing this moves every escrow PDA
+    getEscrowSeedId(competitionId) {
+        return competitionId.replace(/-/g, '').substring(0, SmartContractService.ESCROW_ID_MAX_LENGTH);
+    }
+
+    // Derive escrow PDA for a competition, returns [pda, bump]
+    async deriveEscrowPDA(competitionId) {
+        return await solanaWeb3.PublicKey.findProgramAddress(
+            [
+                Buffer.from(SmartContractService.ESCROW_SEED, "utf8"),
+                Buffer.from(this.getEscrowSeedId(competitionId), "utf8")
+            ],
+            this.programId
+        );
+    }
     
 
 async inspectEscrowAccount(competitionId, escrowAddress) {
@@ -240,6 +369,9 @@ async testTransaction() {
                 throw new Error('Missing required parameters for escrow creation');
             }
             
+            // Validate competition ID before it is used in PDA seeds
+            this.validateCompetitionId(competitionId);
+            
             // NEW: Validate bet amount and platform fee ranges
             if (betAmount < 50000000 || betAmount > 500000000) { // 0.05 to 0.5 SOL in lamports
                 throw new Error('Bet amount must be between 0.05 and 0.5 SOL');
@@ -248,7 +380,22 @@ async testTransaction() {
             if (platformFeeBps < 500 || platformFeeBps > 2500) { // 5% to 25% in basis points
                 throw new Error('Platform fee must be between 5% and 25%');
             }
-            
+
+            // Validate schedule against admin form limits (start delay <= 1h, voting 5-60 min, performance 1-48h)
+            const scheduleNow = Math.floor(Date.now() / 1000);
+            if (!Number.isInteger(votingEndTimeUnix) || !Number.isInteger(competitionEndTimeUnix)) {
+                throw new Error('Competition times must be unix timestamps in seconds');
+            }
+
+            if (votingEndTimeUnix <= scheduleNow || votingEndTimeUnix > scheduleNow + 2 * 60 * 60) { // voting must close within 2 hours
+                throw new Error('Voting must end within the next 2 hours');
+            }
+
+            const performanceSeconds = competitionEndTimeUnix - votingEndTimeUnix;
+            if (performanceSeconds < 60 * 60 || performanceSeconds > 48 * 60 * 60) { // 1 to 48 hours
+                throw new Error('Performance period must be between 1 and 48 hours');
+            }
+
             console.log('💰 Bet amount validation:', betAmount, 'lamports');
             console.log('💳 Platform fee validation:', platformFeeBps, 'basis points');
             
@@ -367,30 +514,9 @@ async testTransaction() {
                 console.error('❌ Transaction send failed:', sendError);
                 
                 // Enhanced error handling for Anchor program errors
-                if (sendError.message.includes('custom program error')) {
-                    const errorCode = sendError.message.match(/custom program error: 0x(\w+)/)?.[1];
-                    if (errorCode) {
-                        const errorNum = parseInt(errorCode, 16);
-                        console.error('🚨 Anchor program error code:', errorNum);
-                        
-                        // Map to your TokenWarsError enum
-                        const errorMappings = {
-                            0: 'InvalidVotingEndTime',
-                            1: 'InvalidCompetitionEndTime', 
-                            2: 'InvalidPlatformFee',
-                            3: 'InvalidCompetition',
-                            4: 'VotingClosed',
-                            5: 'VotingPeriodEnded',
-                            6: 'InsufficientBetAmount',
-                            7: 'UserAlreadyBet',
-                            8: 'Unauthorized',
-                            9: 'VotingStillActive',
-                            10: 'InvalidStatus'
-                        };
-                        
-                        const errorName = errorMappings[errorNum] || `Unknown error ${errorNum}`;
-                        throw new Error(`Program error: ${errorName}`);
-                    }
+                const programError = this.decodeProgramError(sendError);
+                if (programError) {
+                    throw this.toProgramError(programError);
                 }
                 
                 // Provide specific error guidance
@@ -430,10 +556,7 @@ async testTransaction() {
                 }
             }
             
-            if (confirmation.value?.err) {
-                console.error('❌ Transaction failed on-chain:', confirmation.value.err);
-                throw new Error(`Transaction failed on-chain: ${JSON.stringify(confirmation.value.err)}`);
-            }
+            this.assertConfirmed(confirmation);
             
             console.log('✅ Escrow created successfully');
             console.log('🎉 Final result:', {
@@ -460,6 +583,9 @@ async testTransaction() {
             if (error.stack) console.error('Error stack:', error.stack);
             
             // Don't wrap already user-friendly errors
+            if (error.programError) {
+                throw error;
+            }
             if (error.message.includes('rejected') || 
                 error.message.includes('cancelled') || 
                 error.message.includes('insufficient') ||
@@ -522,14 +648,7 @@ async emergencyCleanup(competitionId, adminWallet) {
         }
         
         // Calculate escrow PDA (same logic as createEscrow)
-        const shortId = competitionId.replace(/-/g, '').substring(0, 28);
-        const [escrowAccount] = await solanaWeb3.PublicKey.findProgramAddress(
-            [
-                Buffer.from("escrow", "utf8"),
-                Buffer.from(shortId, "utf8")
-            ],
-            this.programId
-        );
+        const [escrowAccount] = await this.deriveEscrowPDA(competitionId);
 
         console.log('🔑 Emergency cleanup escrow PDA:', escrowAccount.toString());
 
@@ -561,7 +680,8 @@ async emergencyCleanup(competitionId, adminWallet) {
         
         // Confirm transaction
         console.log('⏳ Confirming emergency cleanup transaction...');
-        await this.connection.confirmTransaction(signature, 'confirmed');
+        const confirmation = await this.connection.confirmTransaction(signature, 'confirmed');
+        this.assertConfirmed(confirmation);
         
         console.log('✅ Emergency cleanup transaction completed:', signature);
 
@@ -600,7 +720,10 @@ async emergencyCleanup(competitionId, adminWallet) {
         console.error('❌ Emergency cleanup error:', error);
         
         // Enhanced error reporting for debugging
-        if (error.message.includes('User rejected')) {
+        const programError = this.decodeProgramError(error);
+        if (programError) {
+            throw this.toProgramError(programError, 'Emergency cleanup failed: ');
+        } else if (error.message.includes('User rejected')) {
             throw new Error('Emergency cleanup was rejected by user');
         } else if (error.message.includes('insufficient funds')) {
             throw new Error('Insufficient SOL balance for emergency cleanup transaction');
@@ -640,14 +763,7 @@ async updatePriceSample(competitionId, tokenAPrice, tokenBPrice) {
         }
         
         // Calculate escrow PDA
-        const shortId = competitionId.replace(/-/g, '').substring(0, 28);
-        const [escrowAccount] = await solanaWeb3.PublicKey.findProgramAddress(
-            [
-                Buffer.from("escrow", "utf8"),
-                Buffer.from(shortId, "utf8")
-            ],
-            this.programId
-        );
+        const [escrowAccount] = await this.deriveEscrowPDA(competitionId);
 
         // Build price sample instruction
         const instruction = await this.buildUpdatePriceSampleInstruction({
@@ -672,13 +788,18 @@ async updatePriceSample(competitionId, tokenAPrice, tokenBPrice) {
         
         // Confirm transaction
         console.log('⏳ Confirming price sample transaction...');
-        await this.connection.confirmTransaction(signature, 'confirmed');
+        const confirmation = await this.connection.confirmTransaction(signature, 'confirmed');
+        this.assertConfirmed(confirmation);
         
         console.log('✅ Price sample updated:', signature);
         return signature;
 
     } catch (error) {
         console.error('❌ Price sample update error:', error);
+        const programError = this.decodeProgramError(error);
+        if (programError) {
+            throw this.toProgramError(programError, 'Price sample update failed: ');
+        }
         throw error;
     }
 }
@@ -689,15 +810,8 @@ async updatePriceSample(competitionId, tokenAPrice, tokenBPrice) {
         
         // STEP 1: Generate PDA (Anchor handles account creation automatically), reduced size 32 characters
         console.log('🔑 Generating escrow PDA...');
-        const shortId = accounts.competitionId.replace(/-/g, '').substring(0, 28); // Remove dashes, take first 28 chars
-        
-        const [escrowPDA, bump] = await solanaWeb3.PublicKey.findProgramAddress(
-            [
-                Buffer.from("escrow", "utf8"),        // 6 bytes
-                Buffer.from(shortId, "utf8")          // 28 bytes = 34 total (under 32-byte limit per seed)
-            ],
-            this.programId
-        );
+        const shortId = this.getEscrowSeedId(accounts.competitionId);
+        const [escrowPDA, bump] = await this.deriveEscrowPDA(accounts.competitionId);
         
         console.log('🔑 Escrow PDA:', escrowPDA.toString());
         console.log('🔑 Bump seed:', bump);
@@ -799,7 +913,7 @@ async buildEmergencyCleanupInstruction(accounts) {
     })));
     
     // Serialize instruction data
-    const shortId = accounts.competitionId.replace(/-/g, '').substring(0, 28);
+    const shortId = this.getEscrowSeedId(accounts.competitionId);
     const instructionData = Buffer.concat([
         // Anchor discriminator (8 bytes)
         this.instructions.emergencyCleanup,
@@ -856,7 +970,7 @@ async buildUpdatePriceSampleInstruction(accounts) {
     })));
     
     // Serialize instruction data
-    const shortId = accounts.competitionId.replace(/-/g, '').substring(0, 28);
+    const shortId = this.getEscrowSeedId(accounts.competitionId);
     const instructionData = Buffer.concat([
         // Anchor discriminator (8 bytes)
         this.instructions.updatePriceSample,
@@ -901,16 +1015,10 @@ async buildUpdatePriceSampleInstruction(accounts) {
             
             // FIXED: Use same PDA calculation as createEscrow
             console.log('🔑 Calculating escrow PDA for bet placement...');
-            const shortId = competitionId.replace(/-/g, '').substring(0, 28); // Match createEscrow logic
+            const shortId = this.getEscrowSeedId(competitionId);
             console.log('🔑 Using shortId for PDA:', shortId);
             
-            const [escrowAccount] = await solanaWeb3.PublicKey.findProgramAddress(
-                [
-                    Buffer.from("escrow", "utf8"),        // Match createEscrow exactly
-                    Buffer.from(shortId, "utf8")          // Use shortId, not full competitionId
-                ],
-                this.programId
-            );
+            const [escrowAccount] = await this.deriveEscrowPDA(competitionId);
             
             console.log('🔑 Escrow PDA calculated:', escrowAccount.toString());
             
@@ -995,7 +1103,8 @@ async buildUpdatePriceSampleInstruction(accounts) {
             const signature = await wallet.sendTransaction(transaction, this.connection);
             
             console.log('⏳ Confirming bet transaction...');
-            await this.connection.confirmTransaction(signature, 'confirmed');
+            const confirmation = await this.connection.confirmTransaction(signature, 'confirmed');
+            this.assertConfirmed(confirmation);
             
             console.log('✅ Bet placed successfully, signature:', signature);
             return { signature };
@@ -1004,6 +1113,10 @@ async buildUpdatePriceSampleInstruction(accounts) {
             console.error('❌ Error placing bet:', error);
             console.error('❌ Competition ID:', competitionId);
             console.error('❌ Error details:', error.message);
+            const programError = this.decodeProgramError(error);
+            if (programError) {
+                throw this.toProgramError(programError, 'Bet placement failed: ');
+            }
             throw new Error(`Bet placement failed: ${error.message}`);
         }
     }
@@ -1049,17 +1162,10 @@ async withdrawWinnings(competitionId, userWallet) {
         const wallet = await this.getConnectedWallet();
         
         // ✅ CRITICAL: Use exact same shortId pattern as ALL working functions
-        const shortId = competitionId.replace(/-/g, '').substring(0, 28);
+        const shortId = this.getEscrowSeedId(competitionId);
         console.log('🔑 shortId for PDA:', shortId, '(length:', shortId.length, ')');
         
-        // ✅ CRITICAL: Use shortId for PDA calculation (matches createEscrow pattern)
-        const [escrowAccount] = await solanaWeb3.PublicKey.findProgramAddress(
-            [
-                Buffer.from("escrow", "utf8"),        // Same as ALL working functions
-                Buffer.from(shortId, "utf8")          // ✅ shortId, not full competitionId
-            ],
-            this.programId
-        );
+        const [escrowAccount] = await this.deriveEscrowPDA(competitionId);
         
         console.log('🔑 Escrow PDA:', escrowAccount.toString());
         
@@ -1077,13 +1183,18 @@ async withdrawWinnings(competitionId, userWallet) {
         transaction.feePayer = wallet.publicKey;
         
         const signature = await wallet.sendTransaction(transaction, this.connection);
-        await this.connection.confirmTransaction(signature, 'confirmed');
+        const confirmation = await this.connection.confirmTransaction(signature, 'confirmed');
+        this.assertConfirmed(confirmation);
         
         console.log('✅ Winnings withdrawn successfully, signature:', signature);
         return { signature };
         
     } catch (error) {
         console.error('❌ Error withdrawing winnings:', error);
+        const programError = this.decodeProgramError(error);
+        if (programError) {
+            throw this.toProgramError(programError, 'Withdrawal failed: ');
+        }
         throw new Error(`Withdrawal failed: ${error.message}`);
     }
 }
@@ -1454,7 +1565,8 @@ buildBetMemoText(betAmount, tokenChoice, competitionId) {
             
             // Wait for confirmation
             console.log('⏳ Confirming transaction...');
-            await this.connection.confirmTransaction(signature, 'confirmed');
+            const confirmation = await this.connection.confirmTransaction(signature, 'confirmed');
+            this.assertConfirmed(confirmation);
             
             console.log('✅ Transaction confirmed, signature:', signature);
             return {
@@ -1472,6 +1584,11 @@ buildBetMemoText(betAmount, tokenChoice, competitionId) {
             if (error.logs) console.error('Transaction logs:', error.logs);
             if (error.message) console.error('Error message:', error.message);
             
+            const programError = this.decodeProgramError(error);
+            if (programError) {
+                throw this.toProgramError(programError, 'Transaction failed: ');
+            }
+            
             // Provide more specific error messages
             let errorMessage = 'Unknown transaction error';
             if (error.message.includes('insufficient funds')) {
@@ -1567,10 +1684,7 @@ buildBetMemoText(betAmount, tokenChoice, competitionId) {
     // Get escrow account data
     async getEscrowData(competitionId) {
         try {
-            const [escrowAccount] = await solanaWeb3.PublicKey.findProgramAddress(
-                [Buffer.from("escrow"), Buffer.from(competitionId)],
-                this.programId
-            );
+            const [escrowAccount] = await this.deriveEscrowPDA(competitionId);
             
             const accountInfo = await this.connection.getAccountInfo(escrowAccount);
             if (!accountInfo) {
@@ -1589,6 +1703,45 @@ buildBetMemoText(betAmount, tokenChoice, competitionId) {
             return null;
         }
     }
+
+    /**
+     * Preview payout for a hypothetical stake (no transaction sent)
+     * Pools and stake in integer lamports (Supabase total_pool is SOL - convert first)
+     * Meant for the bet modal next to updateTotalCostDisplay() in app.js, once per-side pools are tracked
+     */
+    previewClaim({ poolA, poolB, side, stake, platformFeeBps }) {
+        if (side !== 'A' && side !== 'B') {
+            throw new Error('Side must be A or B');
+        }
+        if (!Number.isSafeInteger(poolA) || poolA < 0 || !Number.isSafeInteger(poolB) || poolB < 0) {
+            throw new Error('Pools must be non-negative lamport amounts');
+        }
+        if (!Number.isSafeInteger(stake) || stake <= 0) {
+            throw new Error('Stake must be a positive lamport amount');
+        }
+        if (!Number.isInteger(platformFeeBps) || platformFeeBps < 0 || platformFeeBps > 10000) {
+            throw new Error('Platform fee must be between 0 and 10000 basis points');
+        }
+
+        // BigInt keeps the products exact (u64-style integer division)
+        const stakeLamports = BigInt(stake);
+        const sidePool = BigInt(side === 'A' ? poolA : poolB) + stakeLamports;
+        const totalPool = BigInt(poolA) + BigInt(poolB) + stakeLamports;
+
+        // Platform fee comes off the total pool, winners split the rest pro-rata
+        const totalFee = totalPool * BigInt(platformFeeBps) / 10000n;
+        const distributable = totalPool - totalFee;
+        const expectedPayout = Number(distributable * stakeLamports / sidePool);
+        const fee = Number(totalFee * stakeLamports / sidePool);
+
+        return {
+            expectedPayout,
+            fee,
+            profit: expectedPayout - stake,
+            impliedOdds: expectedPayout / stake,
+            impliedProbability: Number(sidePool) / Number(totalPool)
+        };
+    }
 }
 
 window.claimRefund = async function(betId, competitionId) {
@@ -0,0 +1,81 @@
+// Escrow PDA seed tests - a change to the seeds moves every escrow, so these must fail first
+// Expected address computed offline with Solana's create_program_address for the devnet program
+
+global.window = {};
+global.document = {};
+global.solanaWeb3 = require('@solana/web3.js');
+
+const SmartContractService = require('../docs/smart-contract-service');
+
+const PROGRAM_ID = 'GQ5wLSCs3g62xDoaKxgTVtqjsSXHz3FXYjNpBZGDdpqm';
+const COMPETITION_ID = '123e4567-e89b-12d3-a456-426614174000';
+const ESCROW_SEED_ID = '123e4567e89b12d3a45642661417';
+const ESCROW_PDA = 'FpCydkDm8BXahkcEn6UdNGxz56hmvksVvq2Uc3Zbwuqb';
+const ESCROW_BUMP = 253;
+
+describe('escrow PDA derivation', () => {
+    let service;
+
+    beforeAll(() => {
+        jest.spyOn(console, 'log').mockImplementation(() => {});
+        service = new SmartContractService();
+        service.programId = new solanaWeb3.PublicKey(PROGRAM_ID);
+    });
+
+    test('seed constants match the deployed program', () => {
+        expect(SmartContractService.ESCROW_SEED).toBe('escrow');
+        expect(SmartContractService.ESCROW_ID_MAX_LENGTH).toBe(28);
+    });
+
+    test('seed ID strips dashes and keeps the first 28 hex digits', () => {
+        expect(service.getEscrowSeedId(COMPETITION_ID)).toBe(ESCROW_SEED_ID);
+        expect(service.getEscrowSeedId(ESCROW_SEED_ID)).toBe(ESCROW_SEED_ID);
+    });
+
+    test('derives the pinned escrow address and bump', async () => {
+        const [pda, bump] = await service.deriveEscrowPDA(COMPETITION_ID);
+
+        expect(pda.toBase58()).toBe(ESCROW_PDA);
+        expect(bump).toBe(ESCROW_BUMP);
+    });
+
+    test('full ID and seed ID derive the same escrow', async () => {
+        const [fromFullId] = await service.deriveEscrowPDA(COMPETITION_ID);
+        const [fromSeedId] = await service.deriveEscrowPDA(ESCROW_SEED_ID);
+
+        expect(fromSeedId.toBase58()).toBe(fromFullId.toBase58());
+    });
+
+    test('IDs differing only after the 28th hex digit share an escrow', async () => {
+        const [a] = await service.deriveEscrowPDA('123e4567-e89b-12d3-a456-426614170000');
+        const [b] = await service.deriveEscrowPDA('123e4567-e89b-12d3-a456-42661417ffff');
+
+        expect(a.toBase58()).toBe(ESCROW_PDA);
+        expect(b.toBase58()).toBe(ESCROW_PDA);
+    });
+
+    test('seeding with all 32 hex digits gives a different address', async () => {
+        const [untruncated] = await solanaWeb3.PublicKey.findProgramAddress(
+            [Buffer.from('escrow'), Buffer.from('123e4567e89b12d3a456426614174000')],
+            service.programId
+        );
+
+        expect(untruncated.toBase58()).toBe('4rNxNPDqkn5cWv6Z4EAZnvT3eCdfZQbR2BDtqeZYd3iJ');
+        expect(untruncated.toBase58()).not.toBe(ESCROW_PDA);
+    });
+
+    test('getEscrowData reads the same escrow as placeBet and withdrawWinnings', async () => {
+        const requested = [];
+        service.connection = {
+            getAccountInfo: async (publicKey) => {
+                requested.push(publicKey.toBase58());
+                return { lamports: 1, data: Buffer.alloc(0) };
+            }
+        };
+
+        const escrow = await service.getEscrowData(COMPETITION_ID);
+
+        expect(requested).toEqual([ESCROW_PDA]);
+        expect(escrow.address).toBe(ESCROW_PDA);
+    });
+});
@@ -0,0 +1,104 @@
+// previewClaim tests - fee model from README: winners take 85% of the pool, platform takes 15%
+
+global.window = {};
+global.document = {};
+
+const SmartContractService = require('../docs/smart-contract-service');
+
+const PLATFORM_FEE_BPS = 1500;
+
+describe('previewClaim', () => {
+    let service;
+
+    beforeAll(() => {
+        jest.spyOn(console, 'log').mockImplementation(() => {});
+        service = new SmartContractService();
+    });
+
+    test('sole winner receives 85% of the pool', () => {
+        const result = service.previewClaim({
+            poolA: 0,
+            poolB: 900000000,
+            side: 'A',
+            stake: 100000000,
+            platformFeeBps: PLATFORM_FEE_BPS
+        });
+
+        expect(result.expectedPayout).toBe(850000000);
+        expect(result.fee).toBe(150000000);
+        expect(result.profit).toBe(750000000);
+        expect(result.impliedProbability).toBe(0.1);
+    });
+
+    test('winning side splits 85% of the pool pro-rata', () => {
+        const result = service.previewClaim({
+            poolA: 3000000000,
+            poolB: 1000000000,
+            side: 'A',
+            stake: 1000000000,
+            platformFeeBps: PLATFORM_FEE_BPS
+        });
+
+        // 5 SOL pool, 4.25 SOL to winners, stake is 1/4 of side A
+        expect(result.expectedPayout).toBe(1062500000);
+        expect(result.fee).toBe(187500000);
+        expect(result.impliedOdds).toBe(1.0625);
+        expect(result.impliedProbability).toBe(0.8);
+    });
+
+    test('rounds fee and payout down to whole lamports', () => {
+        // total 4, fee floor(0.6) = 0, payout floor(4 * 1 / 3) = 1
+        const result = service.previewClaim({
+            poolA: 2,
+            poolB: 1,
+            side: 'A',
+            stake: 1,
+            platformFeeBps: PLATFORM_FEE_BPS
+        });
+
+        expect(result.expectedPayout).toBe(1);
+        expect(result.fee).toBe(0);
+    });
+
+    test('never pays out more than the distributable pool', () => {
+        const poolA = 123456789;
+        const poolB = 987654321;
+        const stake = 55555555;
+        const result = service.previewClaim({ poolA, poolB, side: 'B', stake, platformFeeBps: PLATFORM_FEE_BPS });
+
+        const totalPool = poolA + poolB + stake;
+        expect(result.expectedPayout).toBeLessThanOrEqual(Math.floor(totalPool * 0.85));
+        expect(result.expectedPayout + result.fee).toBeLessThanOrEqual(totalPool);
+    });
+
+    test('stays exact when products exceed 2^53', () => {
+        // Number math gives 1705093499426879 here - one lamport more than the u64 result
+        const result = service.previewClaim({
+            poolA: 188591313263643,
+            poolB: 298791503847023,
+            side: 'A',
+            stake: 1736472338764210,
+            platformFeeBps: PLATFORM_FEE_BPS
+        });
+
+        expect(result.expectedPayout).toBe(1705093499426878);
+        expect(result.fee).toBe(300898852840037);
+    });
+
+    test.each([
+        ['undefined pool', { poolA: undefined, poolB: 1 }, 'Pools must be non-negative lamport amounts'],
+        ['negative pool', { poolA: -1, poolB: 1 }, 'Pools must be non-negative lamport amounts'],
+        ['SOL float pool', { poolA: 0.5, poolB: 1 }, 'Pools must be non-negative lamport amounts']
+    ])('rejects %s', (_, pools, message) => {
+        expect(() => service.previewClaim({ ...pools, side: 'A', stake: 1, platformFeeBps: PLATFORM_FEE_BPS }))
+            .toThrow(message);
+    });
+
+    test('rejects invalid side, stake and fee', () => {
+        const base = { poolA: 1, poolB: 1, side: 'A', stake: 1, platformFeeBps: PLATFORM_FEE_BPS };
+
+        expect(() => service.previewClaim({ ...base, side: 'C' })).toThrow('Side must be A or B');
+        expect(() => service.previewClaim({ ...base, stake: 0 })).toThrow('Stake must be a positive lamport amount');
+        expect(() => service.previewClaim({ ...base, platformFeeBps: 10001 })).toThrow('Platform fee must be between 0 and 10000 basis points');
+    });
+});